@@ -3,6 +3,14 @@ use std::fmt::Debug;
 pub const SCREEN_WIDTH: usize = 64; // 64 pixels wide
 pub const SCREEN_HEIGHT: usize = 32; // 32 pixels tall
 
+// Super-CHIP extended (hires) resolution.
+pub const HIRES_WIDTH: usize = 128;
+pub const HIRES_HEIGHT: usize = 64;
+
+// The screen buffer is always sized for the larger hires mode; lores mode
+// just uses the leading SCREEN_WIDTH * SCREEN_HEIGHT slice of it.
+const SCREEN_BUFFER_SIZE: usize = HIRES_WIDTH * HIRES_HEIGHT;
+
 const RAM_SIZE: usize = 4096;
 const NUM_REGISTERS: usize = 16; // 16 general-purpose registers
 const STACK_SIZE: usize = 16; // Stack size for subroutine calls
@@ -32,17 +40,110 @@ const FONTSET: [u8; FONTSET_SIZE] = [
     0xF0, 0x80, 0xF0, 0x80, 0x80, // F
 ];
 
+const HIRES_FONTSET_SIZE: usize = 100; // 10 digits, 10 bytes each
+
+// Super-CHIP 8x10 "big font" for digits 0-9.
+const HIRES_FONTSET: [u8; HIRES_FONTSET_SIZE] = [
+    0x3C, 0x7E, 0xE7, 0xC3, 0xC3, 0xC3, 0xC3, 0xE7, 0x7E, 0x3C, // 0
+    0x18, 0x38, 0x58, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x3C, // 1
+    0x3E, 0x7F, 0xC3, 0x06, 0x0C, 0x18, 0x30, 0x60, 0xFF, 0xFF, // 2
+    0x3C, 0x7E, 0xC3, 0x03, 0x0E, 0x0E, 0x03, 0xC3, 0x7E, 0x3C, // 3
+    0x06, 0x0E, 0x1E, 0x36, 0x66, 0xC6, 0xFF, 0xFF, 0x06, 0x06, // 4
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFC, 0xFE, 0x03, 0xC3, 0x7E, 0x3C, // 5
+    0x3E, 0x7C, 0xC0, 0xC0, 0xFC, 0xFE, 0xC3, 0xC3, 0x7E, 0x3C, // 6
+    0xFF, 0xFF, 0x03, 0x06, 0x0C, 0x18, 0x30, 0x30, 0x30, 0x30, // 7
+    0x3C, 0x7E, 0xC3, 0xC3, 0x7E, 0x7E, 0xC3, 0xC3, 0x7E, 0x3C, // 8
+    0x3C, 0x7E, 0xC3, 0xC3, 0x7F, 0x3F, 0x03, 0x03, 0x3E, 0x7C, // 9
+];
+
+/// The tone generated while the sound timer is active.
+const TONE_HZ: u32 = 440;
+const AUDIO_AMPLITUDE: f32 = 0.25;
+
+/// A destination for the square-wave samples generated while `st > 0`.
+///
+/// Implement this for whatever audio backend a frontend uses (e.g. an SDL2
+/// or WASM audio callback) and register it with [`Emu::set_audio_sink`]. The
+/// core stays decoupled from any particular backend.
+pub trait AudioSink {
+    fn push_sample(&mut self, sample: f32);
+}
+
+/// Error returned by [`Emu::load_rom`] when the ROM does not fit in RAM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomLoadError {
+    TooLarge { size: usize, max: usize },
+}
+
+impl std::fmt::Display for RomLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RomLoadError::TooLarge { size, max } => {
+                write!(f, "ROM is {size} bytes, but only {max} bytes are available")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RomLoadError {}
+
+const SNAPSHOT_MAGIC: [u8; 4] = *b"C8SS";
+const SNAPSHOT_VERSION: u8 = 1;
+
+/// Error returned by [`Emu::restore`] when a snapshot is malformed or from an
+/// incompatible [`Emu::snapshot`] version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestoreError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    UnexpectedEof,
+}
+
+impl std::fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreError::BadMagic => write!(f, "not a chip8_core snapshot"),
+            RestoreError::UnsupportedVersion(v) => {
+                write!(f, "snapshot version {v} is not supported")
+            }
+            RestoreError::UnexpectedEof => write!(f, "snapshot data is truncated"),
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
+
+/// Reads and advances past `len` bytes at `*pos`, or errors if `data` is too short.
+fn read_slice<'a>(data: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8], RestoreError> {
+    let end = *pos + len;
+    let slice = data.get(*pos..end).ok_or(RestoreError::UnexpectedEof)?;
+    *pos = end;
+    Ok(slice)
+}
+
 pub struct Emu {
-    pc: u16,                                      // Program Counter, 16 bit
-    ram: [u8; RAM_SIZE],                          // 4K RAM
-    screen: [bool; SCREEN_WIDTH * SCREEN_HEIGHT], // 64x32 pixel screen
-    v_reg: [u8; NUM_REGISTERS],                   // 16 registers (V0 to VF)
-    i_reg: u16,                                   // Index register
-    sp: u16,                                      // Stack pointer
-    stack: [u16; STACK_SIZE],                     // Stack for subroutine calls
-    keys: [bool; NUM_KEYS],                       // Keypad state
-    dt: u8,                                       // Delay timer
-    st: u8,                                       // Sound timer
+    pc: u16,                                // Program Counter, 16 bit
+    ram: [u8; RAM_SIZE],                    // 4K RAM
+    screen: [bool; SCREEN_BUFFER_SIZE],     // Pixel screen, sized for hires mode
+    v_reg: [u8; NUM_REGISTERS],             // 16 registers (V0 to VF)
+    i_reg: u16,                             // Index register
+    sp: u16,                                // Stack pointer
+    stack: [u16; STACK_SIZE],               // Stack for subroutine calls
+    keys: [bool; NUM_KEYS],                 // Keypad state
+    dt: u8,                                 // Delay timer
+    st: u8,                                 // Sound timer
+    hires: bool,                            // Super-CHIP 128x64 extended mode
+    flags: [u8; 8],                         // Super-CHIP persistent RPL flag registers
+    halted: bool,                           // Set by the Super-CHIP 00FD (exit) opcode
+    audio_sink: Option<Box<dyn AudioSink>>, // Pluggable square-wave destination
+    audio_phase: u32,                       // Sample position within the current tone period
+    rng_state: Option<u64>,                 // Deterministic CXKK seed; None falls back to `rand`
+}
+
+impl Default for Emu {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Emu {
@@ -50,7 +151,7 @@ impl Emu {
         let mut new_emu = Self {
             pc: START_ADDRESS,
             ram: [0; RAM_SIZE],
-            screen: [false; SCREEN_WIDTH * SCREEN_HEIGHT],
+            screen: [false; SCREEN_BUFFER_SIZE],
             v_reg: [0; NUM_REGISTERS],
             i_reg: 0,
             sp: 0,
@@ -58,13 +159,238 @@ impl Emu {
             keys: [false; NUM_KEYS],
             dt: 0,
             st: 0,
+            hires: false,
+            flags: [0; 8],
+            halted: false,
+            audio_sink: None,
+            audio_phase: 0,
+            rng_state: None,
         };
 
         new_emu.ram[..FONTSET_SIZE].copy_from_slice(&FONTSET);
+        new_emu.ram[FONTSET_SIZE..FONTSET_SIZE + HIRES_FONTSET_SIZE]
+            .copy_from_slice(&HIRES_FONTSET);
 
         new_emu
     }
 
+    /// Width in pixels of the current display mode (lores or hires).
+    fn width(&self) -> usize {
+        if self.hires {
+            HIRES_WIDTH
+        } else {
+            SCREEN_WIDTH
+        }
+    }
+
+    /// Height in pixels of the current display mode (lores or hires).
+    fn height(&self) -> usize {
+        if self.hires {
+            HIRES_HEIGHT
+        } else {
+            SCREEN_HEIGHT
+        }
+    }
+
+    /// True once a Super-CHIP `00FD` (exit) opcode has run.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Copies `data` into RAM starting at `START_ADDRESS`, as the program area.
+    ///
+    /// Returns an error instead of panicking if the ROM is too large to fit.
+    pub fn load_rom(&mut self, data: &[u8]) -> Result<(), RomLoadError> {
+        let start = START_ADDRESS as usize;
+        let end = start + data.len();
+
+        if end > RAM_SIZE {
+            return Err(RomLoadError::TooLarge {
+                size: data.len(),
+                max: RAM_SIZE - start,
+            });
+        }
+
+        self.ram[start..end].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Returns the display buffer for a frontend to render: 64x32 pixels
+    /// normally, or 128x64 while in Super-CHIP hires mode.
+    pub fn get_display(&self) -> &[bool] {
+        &self.screen[..self.width() * self.height()]
+    }
+
+    /// Updates the pressed state of keypad key `idx` (0x0..=0xF).
+    pub fn keypress(&mut self, idx: usize, pressed: bool) {
+        self.keys[idx] = pressed;
+    }
+
+    /// True while the sound timer is active, i.e. a frontend should be playing a tone.
+    pub fn is_sound_active(&self) -> bool {
+        self.st > 0
+    }
+
+    /// Registers the destination for generated audio samples.
+    pub fn set_audio_sink(&mut self, sink: Box<dyn AudioSink>) {
+        self.audio_sink = Some(sink);
+    }
+
+    /// Generates one square-wave sample at `sample_rate` Hz (silence when the
+    /// sound timer is inactive) and feeds it to the registered [`AudioSink`].
+    ///
+    /// A frontend should call this once per sample at whatever rate its audio
+    /// backend plays at, independently of [`Emu::tick`]/[`Emu::tick_timers`].
+    pub fn push_audio_sample(&mut self, sample_rate: u32) {
+        let sample = if self.st > 0 {
+            let half_period = (sample_rate / (2 * TONE_HZ)).max(1);
+            let sample = if self.audio_phase < half_period {
+                AUDIO_AMPLITUDE
+            } else {
+                -AUDIO_AMPLITUDE
+            };
+            self.audio_phase = (self.audio_phase + 1) % (half_period * 2);
+            sample
+        } else {
+            self.audio_phase = 0;
+            0.0
+        };
+
+        if let Some(sink) = self.audio_sink.as_mut() {
+            sink.push_sample(sample);
+        }
+    }
+
+    /// Seeds `CXKK`'s random source for reproducible runs (save-state replay,
+    /// deterministic test fixtures). Without a seed, `CXKK` draws from `rand`.
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng_state = Some(if seed == 0 { 1 } else { seed });
+    }
+
+    /// Next random byte for `CXKK`: a seeded xorshift64 stream if [`Emu::seed_rng`]
+    /// was called, otherwise a draw from `rand`.
+    fn next_random_byte(&mut self) -> u8 {
+        match self.rng_state.as_mut() {
+            Some(state) => {
+                let mut x = *state;
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                *state = x;
+                (x & 0xFF) as u8
+            }
+            None => rand::random(),
+        }
+    }
+
+    /// Serializes the full machine state into a versioned byte blob, for save
+    /// states and frame-accurate, deterministic replay.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&SNAPSHOT_MAGIC);
+        out.push(SNAPSHOT_VERSION);
+        out.extend_from_slice(&self.pc.to_le_bytes());
+        out.extend_from_slice(&self.ram);
+        out.extend(self.screen.iter().map(|&pixel| pixel as u8));
+        out.extend_from_slice(&self.v_reg);
+        out.extend_from_slice(&self.i_reg.to_le_bytes());
+        out.extend_from_slice(&self.sp.to_le_bytes());
+        for addr in &self.stack {
+            out.extend_from_slice(&addr.to_le_bytes());
+        }
+        out.extend(self.keys.iter().map(|&key| key as u8));
+        out.push(self.dt);
+        out.push(self.st);
+        out.push(self.hires as u8);
+        out.extend_from_slice(&self.flags);
+        out.push(self.halted as u8);
+        match self.rng_state {
+            Some(seed) => {
+                out.push(1);
+                out.extend_from_slice(&seed.to_le_bytes());
+            }
+            None => out.push(0),
+        }
+        out
+    }
+
+    /// Restores machine state previously produced by [`Emu::snapshot`].
+    pub fn restore(&mut self, data: &[u8]) -> Result<(), RestoreError> {
+        if data.len() < SNAPSHOT_MAGIC.len() || data[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+            return Err(RestoreError::BadMagic);
+        }
+
+        let mut pos = SNAPSHOT_MAGIC.len();
+        let version = read_slice(data, &mut pos, 1)?[0];
+        if version != SNAPSHOT_VERSION {
+            return Err(RestoreError::UnsupportedVersion(version));
+        }
+
+        let pc = u16::from_le_bytes(read_slice(data, &mut pos, 2)?.try_into().unwrap());
+
+        let mut ram = [0u8; RAM_SIZE];
+        ram.copy_from_slice(read_slice(data, &mut pos, RAM_SIZE)?);
+
+        let mut screen = [false; SCREEN_BUFFER_SIZE];
+        for (dst, &byte) in screen
+            .iter_mut()
+            .zip(read_slice(data, &mut pos, SCREEN_BUFFER_SIZE)?)
+        {
+            *dst = byte != 0;
+        }
+
+        let mut v_reg = [0u8; NUM_REGISTERS];
+        v_reg.copy_from_slice(read_slice(data, &mut pos, NUM_REGISTERS)?);
+
+        let i_reg = u16::from_le_bytes(read_slice(data, &mut pos, 2)?.try_into().unwrap());
+        let sp = u16::from_le_bytes(read_slice(data, &mut pos, 2)?.try_into().unwrap());
+
+        let mut stack = [0u16; STACK_SIZE];
+        for slot in stack.iter_mut() {
+            *slot = u16::from_le_bytes(read_slice(data, &mut pos, 2)?.try_into().unwrap());
+        }
+
+        let mut keys = [false; NUM_KEYS];
+        for (dst, &byte) in keys.iter_mut().zip(read_slice(data, &mut pos, NUM_KEYS)?) {
+            *dst = byte != 0;
+        }
+
+        let dt = read_slice(data, &mut pos, 1)?[0];
+        let st = read_slice(data, &mut pos, 1)?[0];
+        let hires = read_slice(data, &mut pos, 1)?[0] != 0;
+
+        let mut flags = [0u8; 8];
+        flags.copy_from_slice(read_slice(data, &mut pos, 8)?);
+
+        let halted = read_slice(data, &mut pos, 1)?[0] != 0;
+
+        let has_seed = read_slice(data, &mut pos, 1)?[0];
+        let rng_state = if has_seed != 0 {
+            Some(u64::from_le_bytes(
+                read_slice(data, &mut pos, 8)?.try_into().unwrap(),
+            ))
+        } else {
+            None
+        };
+
+        self.pc = pc;
+        self.ram = ram;
+        self.screen = screen;
+        self.v_reg = v_reg;
+        self.i_reg = i_reg;
+        self.sp = sp;
+        self.stack = stack;
+        self.keys = keys;
+        self.dt = dt;
+        self.st = st;
+        self.hires = hires;
+        self.flags = flags;
+        self.halted = halted;
+        self.rng_state = rng_state;
+
+        Ok(())
+    }
+
     fn push(&mut self, val: u16) {
         self.stack[self.sp as usize] = val;
         self.sp += 1;
@@ -78,7 +404,7 @@ impl Emu {
     pub fn reset(&mut self) {
         self.pc = START_ADDRESS;
         self.ram = [0; RAM_SIZE];
-        self.screen = [false; SCREEN_WIDTH * SCREEN_HEIGHT];
+        self.screen = [false; SCREEN_BUFFER_SIZE];
         self.v_reg = [0; NUM_REGISTERS];
         self.i_reg = 0;
         self.sp = 0;
@@ -86,14 +412,19 @@ impl Emu {
         self.keys = [false; NUM_KEYS];
         self.dt = 0;
         self.st = 0;
+        self.hires = false;
+        self.flags = [0; 8];
+        self.halted = false;
+        self.audio_phase = 0;
         self.ram[..FONTSET_SIZE].copy_from_slice(&FONTSET);
+        self.ram[FONTSET_SIZE..FONTSET_SIZE + HIRES_FONTSET_SIZE].copy_from_slice(&HIRES_FONTSET);
     }
 
     pub fn tick(&mut self) {
         // Fetch
         let op = self.fetch();
-        // Decode
-        // Execute
+        // Decode & Execute
+        self.execute(op);
     }
 
     pub fn fetch(&mut self) -> u16 {
@@ -112,21 +443,361 @@ impl Emu {
         let digit4 = op & 0x000F; // Last nibble
 
         match (digit1, digit2, digit3, digit4) {
-            (0, 0, 0, 0) => return,
+            (0, 0, 0, 0) => (),
             // CLS
             (0, 0, 0xE, 0) => {
-                self.screen = [false; SCREEN_WIDTH * SCREEN_HEIGHT];
+                self.screen = [false; SCREEN_BUFFER_SIZE];
             }
             // RET
             (0, 0, 0xE, 0xE) => {
                 let ret_addr = self.pop();
                 self.pc = ret_addr;
             }
+            // SCHIP: scroll display down N rows
+            (0, 0, 0xC, _) => {
+                let n = digit4 as usize;
+                let w = self.width();
+                let h = self.height();
+                for row in (n..h).rev() {
+                    for col in 0..w {
+                        self.screen[row * w + col] = self.screen[(row - n) * w + col];
+                    }
+                }
+                for row in 0..n.min(h) {
+                    for col in 0..w {
+                        self.screen[row * w + col] = false;
+                    }
+                }
+            }
+            // SCHIP: scroll display right 4 pixels
+            (0, 0, 0xF, 0xB) => {
+                let w = self.width();
+                let h = self.height();
+                for row in 0..h {
+                    for col in (4..w).rev() {
+                        self.screen[row * w + col] = self.screen[row * w + col - 4];
+                    }
+                    for col in 0..4.min(w) {
+                        self.screen[row * w + col] = false;
+                    }
+                }
+            }
+            // SCHIP: scroll display left 4 pixels
+            (0, 0, 0xF, 0xC) => {
+                let w = self.width();
+                let h = self.height();
+                for row in 0..h {
+                    for col in 0..w.saturating_sub(4) {
+                        self.screen[row * w + col] = self.screen[row * w + col + 4];
+                    }
+                    for col in w.saturating_sub(4)..w {
+                        self.screen[row * w + col] = false;
+                    }
+                }
+            }
+            // SCHIP: exit
+            (0, 0, 0xF, 0xD) => {
+                self.halted = true;
+            }
+            // SCHIP: return to lores (64x32)
+            (0, 0, 0xF, 0xE) => {
+                self.hires = false;
+                self.screen = [false; SCREEN_BUFFER_SIZE];
+            }
+            // SCHIP: enter hires (128x64)
+            (0, 0, 0xF, 0xF) => {
+                self.hires = true;
+                self.screen = [false; SCREEN_BUFFER_SIZE];
+            }
             // JMP NNN
             (1, _, _, _) => {
                 let nnn = op & 0xFFF;
                 self.pc = nnn;
             }
+            // CALL NNN
+            (2, _, _, _) => {
+                let nnn = op & 0xFFF;
+                self.push(self.pc);
+                self.pc = nnn;
+            }
+            // SKIP VX == KK
+            (3, _, _, _) => {
+                let x = digit2 as usize;
+                let kk = (op & 0xFF) as u8;
+                if self.v_reg[x] == kk {
+                    self.pc += 2;
+                }
+            }
+            // SKIP VX != KK
+            (4, _, _, _) => {
+                let x = digit2 as usize;
+                let kk = (op & 0xFF) as u8;
+                if self.v_reg[x] != kk {
+                    self.pc += 2;
+                }
+            }
+            // SKIP VX == VY
+            (5, _, _, 0) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                if self.v_reg[x] == self.v_reg[y] {
+                    self.pc += 2;
+                }
+            }
+            // VX = KK
+            (6, _, _, _) => {
+                let x = digit2 as usize;
+                let kk = (op & 0xFF) as u8;
+                self.v_reg[x] = kk;
+            }
+            // VX += KK
+            (7, _, _, _) => {
+                let x = digit2 as usize;
+                let kk = (op & 0xFF) as u8;
+                self.v_reg[x] = self.v_reg[x].wrapping_add(kk);
+            }
+            // VX = VY
+            (8, _, _, 0) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.v_reg[x] = self.v_reg[y];
+            }
+            // VX |= VY
+            (8, _, _, 1) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.v_reg[x] |= self.v_reg[y];
+            }
+            // VX &= VY
+            (8, _, _, 2) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.v_reg[x] &= self.v_reg[y];
+            }
+            // VX ^= VY
+            (8, _, _, 3) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.v_reg[x] ^= self.v_reg[y];
+            }
+            // VX += VY, VF = carry
+            (8, _, _, 4) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let (sum, carry) = self.v_reg[x].overflowing_add(self.v_reg[y]);
+                self.v_reg[x] = sum;
+                self.v_reg[0xF] = carry as u8;
+            }
+            // VX -= VY, VF = NOT borrow
+            (8, _, _, 5) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let (diff, borrow) = self.v_reg[x].overflowing_sub(self.v_reg[y]);
+                self.v_reg[x] = diff;
+                self.v_reg[0xF] = !borrow as u8;
+            }
+            // VX >>= 1, VF = shifted-out bit
+            (8, _, _, 6) => {
+                let x = digit2 as usize;
+                let dropped_bit = self.v_reg[x] & 1;
+                self.v_reg[x] >>= 1;
+                self.v_reg[0xF] = dropped_bit;
+            }
+            // VX = VY - VX, VF = NOT borrow
+            (8, _, _, 7) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let (diff, borrow) = self.v_reg[y].overflowing_sub(self.v_reg[x]);
+                self.v_reg[x] = diff;
+                self.v_reg[0xF] = !borrow as u8;
+            }
+            // VX <<= 1, VF = shifted-out bit
+            (8, _, _, 0xE) => {
+                let x = digit2 as usize;
+                let dropped_bit = (self.v_reg[x] >> 7) & 1;
+                self.v_reg[x] <<= 1;
+                self.v_reg[0xF] = dropped_bit;
+            }
+            // SKIP VX != VY
+            (9, _, _, 0) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                if self.v_reg[x] != self.v_reg[y] {
+                    self.pc += 2;
+                }
+            }
+            // I = NNN
+            (0xA, _, _, _) => {
+                let nnn = op & 0xFFF;
+                self.i_reg = nnn;
+            }
+            // JMP V0 + NNN
+            (0xB, _, _, _) => {
+                let nnn = op & 0xFFF;
+                self.pc = (self.v_reg[0] as u16) + nnn;
+            }
+            // VX = rand() & KK
+            (0xC, _, _, _) => {
+                let x = digit2 as usize;
+                let kk = (op & 0xFF) as u8;
+                let rng = self.next_random_byte();
+                self.v_reg[x] = rng & kk;
+            }
+            // SCHIP: DRAW a 16x16 sprite at (VX, VY), VF = collision
+            (0xD, _, _, 0) => {
+                let x = self.v_reg[digit2 as usize] as usize;
+                let y = self.v_reg[digit3 as usize] as usize;
+                let w = self.width();
+                let h = self.height();
+
+                let mut flipped = false;
+                for row in 0..16 {
+                    let sprite = ((self.ram[self.i_reg as usize + row * 2] as u16) << 8)
+                        | self.ram[self.i_reg as usize + row * 2 + 1] as u16;
+                    for col in 0..16 {
+                        // MSB first
+                        if sprite & (0x8000 >> col) != 0 {
+                            let px = (x + col) % w;
+                            let py = (y + row) % h;
+                            let idx = py * w + px;
+                            flipped |= self.screen[idx];
+                            self.screen[idx] ^= true;
+                        }
+                    }
+                }
+
+                self.v_reg[0xF] = flipped as u8;
+            }
+            // DRAW sprite at (VX, VY) with height N, VF = collision
+            (0xD, _, _, _) => {
+                let x = self.v_reg[digit2 as usize] as usize;
+                let y = self.v_reg[digit3 as usize] as usize;
+                let n = digit4 as usize;
+                let w = self.width();
+                let h = self.height();
+
+                let mut flipped = false;
+                for row in 0..n {
+                    let sprite = self.ram[self.i_reg as usize + row];
+                    for col in 0..8 {
+                        // MSB first
+                        if sprite & (0x80 >> col) != 0 {
+                            let px = (x + col) % w;
+                            let py = (y + row) % h;
+                            let idx = py * w + px;
+                            flipped |= self.screen[idx];
+                            self.screen[idx] ^= true;
+                        }
+                    }
+                }
+
+                self.v_reg[0xF] = flipped as u8;
+            }
+            // SKIP key in VX pressed
+            (0xE, _, 9, 0xE) => {
+                let x = digit2 as usize;
+                if self.keys[self.v_reg[x] as usize] {
+                    self.pc += 2;
+                }
+            }
+            // SKIP key in VX not pressed
+            (0xE, _, 0xA, 1) => {
+                let x = digit2 as usize;
+                if !self.keys[self.v_reg[x] as usize] {
+                    self.pc += 2;
+                }
+            }
+            // FX07 - VX = DT
+            (0xF, _, 0, 7) => {
+                let x = digit2 as usize;
+                self.v_reg[x] = self.dt;
+            }
+            // FX0A - block until a key is pressed, then VX = key
+            (0xF, _, 0, 0xA) => {
+                let x = digit2 as usize;
+                let mut pressed = false;
+                for (idx, &key) in self.keys.iter().enumerate() {
+                    if key {
+                        self.v_reg[x] = idx as u8;
+                        pressed = true;
+                        break;
+                    }
+                }
+
+                if !pressed {
+                    // No key down yet: re-run this instruction next tick.
+                    self.pc -= 2;
+                }
+            }
+            // FX15 - DT = VX
+            (0xF, _, 1, 5) => {
+                let x = digit2 as usize;
+                self.dt = self.v_reg[x];
+            }
+            // FX18 - ST = VX
+            (0xF, _, 1, 8) => {
+                let x = digit2 as usize;
+                self.st = self.v_reg[x];
+            }
+            // FX1E - I += VX
+            (0xF, _, 1, 0xE) => {
+                let x = digit2 as usize;
+                self.i_reg = self.i_reg.wrapping_add(self.v_reg[x] as u16);
+            }
+            // FX29 - I = address of font char in VX
+            (0xF, _, 2, 9) => {
+                let x = digit2 as usize;
+                self.i_reg = (self.v_reg[x] as u16) * 5;
+            }
+            // SCHIP FX30 - I = address of hires font char in VX
+            (0xF, _, 3, 0) => {
+                let x = digit2 as usize;
+                self.i_reg = (FONTSET_SIZE as u16) + (self.v_reg[x] as u16) * 10;
+            }
+            // FX33 - BCD of VX into ram[I..I+3]
+            (0xF, _, 3, 3) => {
+                let x = digit2 as usize;
+                let vx = self.v_reg[x] as f32;
+                let hundreds = (vx / 100.0).floor() as u8;
+                let tens = ((vx / 10.0) % 10.0).floor() as u8;
+                let ones = (vx % 10.0) as u8;
+                let i = self.i_reg as usize;
+                self.ram[i] = hundreds;
+                self.ram[i + 1] = tens;
+                self.ram[i + 2] = ones;
+            }
+            // FX55 - dump V0..=VX into ram[I..]
+            (0xF, _, 5, 5) => {
+                let x = digit2 as usize;
+                let i = self.i_reg as usize;
+                for idx in 0..=x {
+                    self.ram[i + idx] = self.v_reg[idx];
+                }
+            }
+            // FX65 - load V0..=VX from ram[I..]
+            (0xF, _, 6, 5) => {
+                let x = digit2 as usize;
+                let i = self.i_reg as usize;
+                for idx in 0..=x {
+                    self.v_reg[idx] = self.ram[i + idx];
+                }
+            }
+            // SCHIP FX75 - save V0..=VX into the persistent RPL flag registers
+            // (X is capped at 7: there are only 8 flag registers)
+            (0xF, _, 7, 5) => {
+                let x = digit2 as usize;
+                for idx in 0..=x.min(7) {
+                    self.flags[idx] = self.v_reg[idx];
+                }
+            }
+            // SCHIP FX85 - restore V0..=VX from the persistent RPL flag registers
+            // (X is capped at 7: there are only 8 flag registers)
+            (0xF, _, 8, 5) => {
+                let x = digit2 as usize;
+                for idx in 0..=x.min(7) {
+                    self.v_reg[idx] = self.flags[idx];
+                }
+            }
             (_, _, _, _) => unimplemented!("Opcode {op:04X} not implemented yet"),
         }
     }
@@ -137,11 +808,6 @@ impl Emu {
         }
 
         if self.st > 0 {
-            if self.st == 1 {
-                // Play sound (this is a placeholder, actual sound handling would be more complex)
-                println!("Beep!");
-            }
-
             self.st -= 1;
         }
     }